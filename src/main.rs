@@ -104,6 +104,12 @@ pub mod commands {
         /// optional [`Slug`]. If a [`Slug`] is not provided, the service will generate
         /// one. Returns the newly created [`ShortLink`].
         ///
+        /// If no [`Slug`] is given and the `url` already has a short link,
+        /// the existing [`ShortLink`] is returned instead of minting a
+        /// duplicate. Idempotency only applies to auto-generated slugs; an
+        /// explicitly requested [`Slug`] that collides still errors with
+        /// [`ShortenerError::SlugAlreadyInUse`].
+        ///
         /// ## Errors
         ///
         /// See [`ShortenerError`].
@@ -116,12 +122,31 @@ pub mod commands {
         /// Processes a redirection by [`Slug`], returning the associated
         /// [`ShortLink`] or a [`ShortenerError`].
         fn handle_redirect(&mut self, slug: Slug) -> Result<ShortLink, ShortenerError>;
+
+        /// Creates many short links in one call, processing `items` in order
+        /// and returning a per-item result so a single invalid URL or slug
+        /// collision doesn't abort the rest of the batch.
+        ///
+        /// Each successful item appends its own [`Event::LinkCreated`], just
+        /// like calling [`handle_create_short_link`] individually.
+        ///
+        /// [`Event::LinkCreated`]: super::Event::LinkCreated
+        /// [`handle_create_short_link`]: Self::handle_create_short_link
+        fn handle_create_short_links(
+            &mut self,
+            items: Vec<(Url, Option<Slug>)>,
+        ) -> Vec<Result<ShortLink, ShortenerError>> {
+            items
+                .into_iter()
+                .map(|(url, slug)| self.handle_create_short_link(url, slug))
+                .collect()
+        }
     }
 }
 
 /// Queries for CQRS
 pub mod queries {
-    use super::{ShortenerError, Slug, Stats};
+    use super::{Event, ShortenerError, Slug, Stats};
 
     /// Trait for query handlers.
     pub trait QueryHandler {
@@ -130,40 +155,173 @@ pub mod queries {
         ///
         /// [`ShortLink`]: super::ShortLink
         fn get_stats(&self, slug: Slug) -> Result<Stats, ShortenerError>;
+
+        /// Returns the full, ordered event log, turning the otherwise opaque
+        /// event store into a queryable audit subsystem.
+        fn get_events(&self) -> &[Event];
+
+        /// Returns the audit trail of a single link: its creation followed
+        /// by each redirect, paired with the monotonic position (sequence
+        /// number) of the event in the store.
+        ///
+        /// ## Errors
+        ///
+        /// Returns [`ShortenerError::SlugNotFound`] if `slug` never appears
+        /// in any [`Event::LinkCreated`].
+        fn get_link_history(&self, slug: Slug) -> Result<Vec<(u64, &Event)>, ShortenerError>;
     }
 }
 
 /// Events for Event Sourcing
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     LinkCreated(ShortLink),
     LinkRedirected(Slug),
 }
 
+/// URL-safe alphabet used to draw random slug characters.
+const SLUG_ALPHABET: &[u8; 62] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Length of an auto-generated [`Slug`] used by [`UrlShortenerService::new`].
+const DEFAULT_SLUG_LENGTH: usize = 6;
+
+/// Number of times a slug of a given length is re-drawn on collision before
+/// the length is increased, guaranteeing the retry loop terminates.
+const MAX_RETRIES_PER_LENGTH: usize = 10;
+
 /// CQRS and Event Sourcing-based service implementation
 pub struct UrlShortenerService {
     links: HashMap<Slug, ShortLink>,
     stats: HashMap<Slug, Stats>,
     event_store: Vec<Event>,
+    rng_state: u64,
+    slug_length: usize,
 }
 
 impl UrlShortenerService {
-    /// Creates a new instance of the service
+    /// Creates a new instance of the service, generating slugs of
+    /// [`DEFAULT_SLUG_LENGTH`] characters.
     pub fn new() -> Self {
+        Self::with_slug_length(DEFAULT_SLUG_LENGTH)
+    }
+
+    /// Creates a new instance of the service that generates auto-assigned
+    /// slugs of `slug_length` characters.
+    pub fn with_slug_length(slug_length: usize) -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as u64;
+
         Self {
             links: HashMap::new(),
             stats: HashMap::new(),
             event_store: Vec::new(),
+            rng_state: seed,
+            slug_length,
+        }
+    }
+
+    /// Advances the xorshift/SplitMix64 PRNG and returns the next value.
+    fn next_random(&mut self) -> u64 {
+        self.rng_state = self.rng_state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Draws a random [`Slug`] of `len` characters from [`SLUG_ALPHABET`].
+    fn random_slug(&mut self, len: usize) -> Slug {
+        let mut slug = String::with_capacity(len);
+        for _ in 0..len {
+            let idx = (self.next_random() % SLUG_ALPHABET.len() as u64) as usize;
+            slug.push(SLUG_ALPHABET[idx] as char);
+        }
+        Slug(slug)
+    }
+
+    /// Draws a [`Slug`] that is not already present in `links`, lengthening
+    /// it by one character after [`MAX_RETRIES_PER_LENGTH`] collisions so the
+    /// loop always terminates.
+    fn generate_unique_slug(&mut self) -> Slug {
+        let mut len = self.slug_length;
+        loop {
+            for _ in 0..MAX_RETRIES_PER_LENGTH {
+                let candidate = self.random_slug(len);
+                if !self.links.contains_key(&candidate) {
+                    return candidate;
+                }
+            }
+            len += 1;
         }
     }
 }
 
+impl UrlShortenerService {
+    /// Reconstructs a service purely from an ordered event log, proving the
+    /// event store is the single source of truth for `links` and `stats`.
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut service = Self::new();
+        service.event_store = events.to_vec();
+        service.rebuild();
+        service
+    }
+
+    /// Clears `links` and `stats` and folds over `event_store` to
+    /// deterministically rebuild them from scratch.
+    fn rebuild(&mut self) {
+        self.links.clear();
+        self.stats.clear();
+
+        for event in &self.event_store {
+            match event {
+                Event::LinkCreated(link) => {
+                    self.links.insert(link.slug.clone(), link.clone());
+                    self.stats.insert(
+                        link.slug.clone(),
+                        Stats {
+                            link: link.clone(),
+                            redirects: 0,
+                        },
+                    );
+                }
+                Event::LinkRedirected(slug) => {
+                    if let Some(stat) = self.stats.get_mut(slug) {
+                        stat.redirects += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Validates that `url` has a supported scheme (`http`/`https`) and a
+    /// non-empty host, without pulling in a full URL-parsing dependency.
+    fn validate_url(url: &Url) -> Result<(), ShortenerError> {
+        let rest = url
+            .0
+            .strip_prefix("https://")
+            .or_else(|| url.0.strip_prefix("http://"))
+            .ok_or(ShortenerError::InvalidUrl)?;
+
+        let host = rest.split('/').next().unwrap_or("");
+        if host.is_empty() {
+            return Err(ShortenerError::InvalidUrl);
+        }
+
+        Ok(())
+    }
+}
+
 impl commands::CommandHandler for UrlShortenerService {
     fn handle_create_short_link(
         &mut self,
         url: Url,
         slug: Option<Slug>,
     ) -> Result<ShortLink, ShortenerError> {
+        Self::validate_url(&url)?;
+
         let slug = match slug {
             Some(s) => {
                 if self.links.contains_key(&s) {
@@ -172,23 +330,11 @@ impl commands::CommandHandler for UrlShortenerService {
                 s
             }
             None => {
-                let random_slug = Slug(
-                    SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap()
-                        .subsec_nanos()
-                        .to_string(),
-                );
-                while self.links.contains_key(&random_slug) {
-                    let random_slug = Slug(
-                        SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap()
-                            .subsec_nanos()
-                            .to_string(),
-                    );
+                if let Some(existing) = self.links.values().find(|link| link.url == url) {
+                    return Ok(existing.clone());
                 }
-                random_slug
+
+                self.generate_unique_slug()
             }
         };
 
@@ -230,6 +376,31 @@ impl queries::QueryHandler for UrlShortenerService {
             .cloned()
             .ok_or(ShortenerError::SlugNotFound)
     }
+
+    fn get_events(&self) -> &[Event] {
+        &self.event_store
+    }
+
+    fn get_link_history(&self, slug: Slug) -> Result<Vec<(u64, &Event)>, ShortenerError> {
+        let was_created = self
+            .event_store
+            .iter()
+            .any(|event| matches!(event, Event::LinkCreated(link) if link.slug == slug));
+        if !was_created {
+            return Err(ShortenerError::SlugNotFound);
+        }
+
+        Ok(self
+            .event_store
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| match event {
+                Event::LinkCreated(link) => link.slug == slug,
+                Event::LinkRedirected(s) => *s == slug,
+            })
+            .map(|(seq, event)| (seq as u64, event))
+            .collect())
+    }
 }
 
 fn main() {
@@ -249,3 +420,212 @@ fn main() {
     let stats = service.get_stats(slug.slug.clone()).unwrap();
     println!("Stats: {:?}", stats);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_well_formed_http_and_https_urls() {
+        assert_eq!(
+            UrlShortenerService::validate_url(&Url("http://example.com".to_string())),
+            Ok(())
+        );
+        assert_eq!(
+            UrlShortenerService::validate_url(&Url(
+                "https://example.com/some/path".to_string()
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_missing_or_unsupported_scheme() {
+        assert_eq!(
+            UrlShortenerService::validate_url(&Url("example.com".to_string())),
+            Err(ShortenerError::InvalidUrl)
+        );
+        assert_eq!(
+            UrlShortenerService::validate_url(&Url("ftp://example.com".to_string())),
+            Err(ShortenerError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn rejects_empty_host() {
+        assert_eq!(
+            UrlShortenerService::validate_url(&Url("https:///path".to_string())),
+            Err(ShortenerError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn handle_create_short_link_rejects_invalid_url_without_side_effects() {
+        let mut service = UrlShortenerService::new();
+
+        let result =
+            service.handle_create_short_link(Url("not-a-url".to_string()), None);
+
+        assert_eq!(result, Err(ShortenerError::InvalidUrl));
+        assert!(service.links.is_empty());
+        assert!(service.stats.is_empty());
+        assert!(service.event_store.is_empty());
+    }
+
+    #[test]
+    fn rebuilding_from_the_event_log_reproduces_live_state() {
+        let mut service = UrlShortenerService::new();
+        let first = service
+            .handle_create_short_link(Url("https://example.com".to_string()), None)
+            .unwrap();
+        let second = service
+            .handle_create_short_link(
+                Url("https://rust-lang.org".to_string()),
+                Some(Slug("rust".to_string())),
+            )
+            .unwrap();
+
+        service.handle_redirect(first.slug.clone()).unwrap();
+        service.handle_redirect(first.slug.clone()).unwrap();
+        service.handle_redirect(second.slug.clone()).unwrap();
+
+        let rebuilt = UrlShortenerService::from_events(&service.event_store);
+
+        assert_eq!(rebuilt.links, service.links);
+        assert_eq!(rebuilt.stats, service.stats);
+    }
+
+    #[test]
+    fn shortening_the_same_url_twice_returns_the_existing_slug() {
+        let mut service = UrlShortenerService::new();
+        let url = Url("https://example.com".to_string());
+
+        let first = service
+            .handle_create_short_link(url.clone(), None)
+            .unwrap();
+        let second = service
+            .handle_create_short_link(url.clone(), None)
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(service.event_store.len(), 1);
+    }
+
+    #[test]
+    fn an_explicit_slug_still_errors_on_collision_with_an_auto_generated_one() {
+        let mut service = UrlShortenerService::new();
+        let first = service
+            .handle_create_short_link(Url("https://example.com".to_string()), None)
+            .unwrap();
+
+        let result = service.handle_create_short_link(
+            Url("https://rust-lang.org".to_string()),
+            Some(first.slug),
+        );
+
+        assert_eq!(result, Err(ShortenerError::SlugAlreadyInUse));
+    }
+
+    #[test]
+    fn auto_generated_slugs_use_the_configured_length_and_alphabet() {
+        let mut service = UrlShortenerService::with_slug_length(10);
+        let link = service
+            .handle_create_short_link(Url("https://example.com".to_string()), None)
+            .unwrap();
+
+        assert_eq!(link.slug.0.len(), 10);
+        assert!(link.slug.0.bytes().all(|b| b.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn auto_generated_slugs_are_unique_across_many_links() {
+        let mut service = UrlShortenerService::with_slug_length(1);
+        let mut slugs = std::collections::HashSet::new();
+
+        for i in 0..80 {
+            let link = service
+                .handle_create_short_link(Url(format!("https://example.com/{i}")), None)
+                .unwrap();
+            assert!(slugs.insert(link.slug));
+        }
+    }
+
+    #[test]
+    fn batch_create_reports_a_result_per_item_without_aborting_on_failure() {
+        let mut service = UrlShortenerService::new();
+        service
+            .handle_create_short_link(
+                Url("https://taken.example.com".to_string()),
+                Some(Slug("taken".to_string())),
+            )
+            .unwrap();
+
+        let results = service.handle_create_short_links(vec![
+            (Url("https://example.com".to_string()), None),
+            (Url("not-a-url".to_string()), None),
+            (
+                Url("https://rust-lang.org".to_string()),
+                Some(Slug("taken".to_string())),
+            ),
+            (
+                Url("https://docs.rs".to_string()),
+                Some(Slug("docs".to_string())),
+            ),
+        ]);
+
+        assert!(results[0].is_ok());
+        assert_eq!(results[1], Err(ShortenerError::InvalidUrl));
+        assert_eq!(results[2], Err(ShortenerError::SlugAlreadyInUse));
+        assert!(results[3].is_ok());
+
+        // Only the three successful items (one pre-existing + two from the
+        // batch) should have produced `LinkCreated` events.
+        assert_eq!(service.event_store.len(), 3);
+    }
+
+    #[test]
+    fn get_events_exposes_the_full_ordered_log() {
+        let mut service = UrlShortenerService::new();
+        let link = service
+            .handle_create_short_link(Url("https://example.com".to_string()), None)
+            .unwrap();
+        service.handle_redirect(link.slug).unwrap();
+
+        assert_eq!(service.get_events(), service.event_store.as_slice());
+    }
+
+    #[test]
+    fn get_link_history_returns_creation_then_redirects_with_sequence_numbers() {
+        let mut service = UrlShortenerService::new();
+        let other = service
+            .handle_create_short_link(Url("https://rust-lang.org".to_string()), None)
+            .unwrap();
+        let link = service
+            .handle_create_short_link(Url("https://example.com".to_string()), None)
+            .unwrap();
+        service.handle_redirect(other.slug).unwrap();
+        service.handle_redirect(link.slug.clone()).unwrap();
+        service.handle_redirect(link.slug.clone()).unwrap();
+
+        let history = service.get_link_history(link.slug.clone()).unwrap();
+
+        assert_eq!(
+            history,
+            vec![
+                (1, &Event::LinkCreated(link.clone())),
+                (3, &Event::LinkRedirected(link.slug.clone())),
+                (4, &Event::LinkRedirected(link.slug)),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_link_history_rejects_an_unknown_slug() {
+        let service = UrlShortenerService::new();
+
+        assert_eq!(
+            service.get_link_history(Slug("missing".to_string())),
+            Err(ShortenerError::SlugNotFound)
+        );
+    }
+}